@@ -1,48 +1,194 @@
-use hex::encode;
-use napi::{bindgen_prelude::*, Error, Status};
+use hex::{decode, encode};
+use napi::{bindgen_prelude::*, Env, Error, Status, Task};
 use napi_derive::napi;
 use rayon::prelude::*;
+use std::collections::HashSet;
+use std::fs;
+use std::sync::Arc;
 
 // KZG library imports
 use kzg::{
-  eip_4844::{blob_to_kzg_commitment_raw, load_trusted_setup_rust},
-  eth::eip_7594::{compute_cells_and_kzg_proofs_raw, CellsKzgProofs},
-  eth::{BYTES_PER_BLOB, BYTES_PER_PROOF},
+  eip_4844::{
+    blob_to_kzg_commitment_raw, load_trusted_setup_filename_rust, load_trusted_setup_rust,
+    verify_blob_kzg_proof_raw, verify_kzg_proof_raw,
+  },
+  eth::eip_7594::{
+    compute_cells_and_kzg_proofs_raw, recover_cells_and_kzg_proofs_raw,
+    verify_cell_kzg_proof_batch_raw, CellsKzgProofs,
+  },
+  eth::{
+    BYTES_PER_BLOB, BYTES_PER_CELL, BYTES_PER_FIELD_ELEMENT, BYTES_PER_PROOF, CELLS_PER_EXT_BLOB,
+    FIELD_ELEMENTS_PER_BLOB,
+  },
   G1,
 };
 use rust_kzg_blst::eip_7594::BlstBackend;
 use rust_kzg_blst::types::kzg_settings::FsKZGSettings;
+use serde::Deserialize;
+
+/// Compressed size of a single G1 point, as used in both the monomial and Lagrange point lists.
+const BYTES_PER_G1_POINT: usize = 48;
+
+/// Either a "0x"-prefixed hex string or a raw byte array, accepted symmetrically with how
+/// outputs (commitments, proofs) are produced.
+type HexOrBytes = Either<String, Uint8Array>;
+
+/// JSON bundle form of the trusted setup: hex-encoded points, grouped by form.
+#[derive(Deserialize)]
+struct TrustedSetupJson {
+  g1_monomial: Vec<String>,
+  g1_lagrange: Vec<String>,
+  g2_monomial: Vec<String>,
+}
+
+/// Result of recovering a full 128-cell set from a partial subset.
+#[napi(object)]
+pub struct RecoveredCells {
+  /// All 128 cells, each as a "0x..." prefixed hex string.
+  pub cells: Vec<String>,
+  /// The matching KZG proof for each recovered cell, as a "0x..." prefixed hex string.
+  pub proofs: Vec<String>,
+}
 
 /// KZG wrapper class, holding the Trusted Setup parameters for computation.
 /// The internal settings are thread-safe and used across parallel operations.
 #[napi]
 pub struct KzgWrapper {
-  settings: FsKZGSettings,
+  /// Shared behind an `Arc` so cloning a wrapper for an `AsyncTask` (which must own its data) is
+  /// a cheap refcount bump rather than a deep copy of the whole trusted setup/FFT tables.
+  settings: Arc<FsKZGSettings>,
+  /// The maximum accepted input length in bytes. This only bounds/pads blob input; the trusted
+  /// setup and FFT domain underneath are always mainnet-sized (`BYTES_PER_BLOB`). The `load_*`
+  /// factories reject any trusted setup that isn't mainnet-sized, so the loaded domain and this
+  /// bound can never disagree.
+  bytes_per_blob: usize,
 }
 
 #[napi]
 impl KzgWrapper {
   /// Loads the KZG Trusted Setup from G1/G2 monomial and Lagrange byte arrays.
+  /// - `field_elements_per_blob`: Optional maximum accepted blob length, in field elements;
+  ///   shorter input is zero-padded up to it. Defaults to the mainnet `FIELD_ELEMENTS_PER_BLOB`.
+  ///   This does not load a differently-sized trusted setup or FFT domain: the commitment is
+  ///   always computed over the full mainnet-sized (zero-padded) blob, so the point lists must
+  ///   themselves be mainnet-sized — a genuinely different-domain trusted setup (e.g. a real
+  ///   minimal preset) is rejected rather than silently mismatched against the padded blob.
   /// Returns: KzgWrapper instance or a specific error message on failure.
   #[napi(factory)]
   pub fn load_kzg(
     g1_monomial_bytes: Uint8Array,
     g1_lagrange_bytes: Uint8Array,
     g2_monomial_bytes: Uint8Array,
+    field_elements_per_blob: Option<u32>,
   ) -> Result<Self> {
-    let settings = load_trusted_setup_rust(
-      g1_monomial_bytes.as_ref(),
-      g1_lagrange_bytes.as_ref(),
-      g2_monomial_bytes.as_ref(),
-    )
-    .map_err(|e| {
+    Self::validate_mainnet_g1_byte_length(g1_monomial_bytes.as_ref().len(), "g1_monomial_bytes")?;
+    Self::validate_mainnet_g1_byte_length(g1_lagrange_bytes.as_ref().len(), "g1_lagrange_bytes")?;
+
+    let settings = Arc::new(
+      load_trusted_setup_rust(
+        g1_monomial_bytes.as_ref(),
+        g1_lagrange_bytes.as_ref(),
+        g2_monomial_bytes.as_ref(),
+      )
+      .map_err(|e| {
+        Error::new(
+          Status::GenericFailure,
+          format!("Failed to load trusted setup: {:?}", e),
+        )
+      })?,
+    );
+    let bytes_per_blob = Self::resolve_bytes_per_blob(field_elements_per_blob)?;
+
+    Ok(Self {
+      settings,
+      bytes_per_blob,
+    })
+  }
+
+  /// Loads the KZG Trusted Setup from the standard `trusted_setup.txt` file: a counts line
+  /// followed by the hex-encoded G1 Lagrange points and then the G2 monomial points.
+  /// - `file_path`: Path to the trusted setup file.
+  /// - `field_elements_per_blob`: Optional maximum accepted blob length, in field elements; see
+  ///   `load_kzg` for what this does and does not change — a non-mainnet-sized file is rejected.
+  /// Returns: KzgWrapper instance or a specific error message on failure.
+  #[napi(factory)]
+  pub fn load_kzg_from_file(file_path: String, field_elements_per_blob: Option<u32>) -> Result<Self> {
+    let counts_line = fs::read_to_string(&file_path)
+      .map_err(|e| {
+        Error::new(
+          Status::GenericFailure,
+          format!("Failed to read trusted setup file: {:?}", e),
+        )
+      })?
+      .lines()
+      .next()
+      .ok_or_else(|| Error::new(Status::InvalidArg, "Trusted setup file is empty".to_string()))?
+      .to_string();
+    let field_elements_in_file: usize = counts_line
+      .split_whitespace()
+      .next()
+      .and_then(|count| count.parse().ok())
+      .ok_or_else(|| {
+        Error::new(
+          Status::InvalidArg,
+          "Trusted setup file has a malformed counts line".to_string(),
+        )
+      })?;
+    Self::validate_mainnet_point_count(field_elements_in_file, "trusted setup file")?;
+
+    let settings: Arc<FsKZGSettings> = Arc::new(
+      load_trusted_setup_filename_rust(&file_path).map_err(|e| {
+        Error::new(
+          Status::GenericFailure,
+          format!("Failed to load trusted setup from file: {:?}", e),
+        )
+      })?,
+    );
+    let bytes_per_blob = Self::resolve_bytes_per_blob(field_elements_per_blob)?;
+
+    Ok(Self {
+      settings,
+      bytes_per_blob,
+    })
+  }
+
+  /// Loads the KZG Trusted Setup from the JSON bundle form, i.e. an object with
+  /// `g1_monomial`, `g1_lagrange` and `g2_monomial` arrays of hex-encoded points.
+  /// - `json_bytes`: The raw JSON document bytes.
+  /// - `field_elements_per_blob`: Optional maximum accepted blob length, in field elements; see
+  ///   `load_kzg` for what this does and does not change — a non-mainnet-sized bundle is rejected.
+  /// Returns: KzgWrapper instance or a specific error message on failure.
+  #[napi(factory)]
+  pub fn load_kzg_from_json(json_bytes: Uint8Array, field_elements_per_blob: Option<u32>) -> Result<Self> {
+    let trusted_setup: TrustedSetupJson = serde_json::from_slice(json_bytes.as_ref()).map_err(|e| {
       Error::new(
-        Status::GenericFailure,
-        format!("Failed to load trusted setup: {:?}", e),
+        Status::InvalidArg,
+        format!("Failed to parse trusted setup JSON: {:?}", e),
       )
     })?;
+    Self::validate_mainnet_point_count(trusted_setup.g1_monomial.len(), "g1_monomial")?;
+    Self::validate_mainnet_point_count(trusted_setup.g1_lagrange.len(), "g1_lagrange")?;
+
+    let g1_monomial_bytes = Self::concat_hex_points(&trusted_setup.g1_monomial)?;
+    let g1_lagrange_bytes = Self::concat_hex_points(&trusted_setup.g1_lagrange)?;
+    let g2_monomial_bytes = Self::concat_hex_points(&trusted_setup.g2_monomial)?;
+
+    let settings = Arc::new(
+      load_trusted_setup_rust(&g1_monomial_bytes, &g1_lagrange_bytes, &g2_monomial_bytes).map_err(
+        |e| {
+          Error::new(
+            Status::GenericFailure,
+            format!("Failed to load trusted setup: {:?}", e),
+          )
+        },
+      )?,
+    );
+    let bytes_per_blob = Self::resolve_bytes_per_blob(field_elements_per_blob)?;
 
-    Ok(Self { settings })
+    Ok(Self {
+      settings,
+      bytes_per_blob,
+    })
   }
 
   /// Converts a single blob into a KZG commitment.
@@ -53,6 +199,28 @@ impl KzgWrapper {
     self.process_single_commitment(&blob_bytes)
   }
 
+  /// Raw-bytes variant of `blob_to_commitment` for throughput-sensitive callers: skips the hex
+  /// round trip entirely (input blobs are still copied once into a fixed-size array, as the
+  /// backend's entry points take blobs by value).
+  /// - `blob_bytes`: The blob byte array.
+  /// Returns: The raw 48-byte commitment.
+  #[napi]
+  pub fn blob_to_commitment_raw_bytes(&self, blob_bytes: Uint8Array) -> Result<Buffer> {
+    self.process_single_commitment_raw(&blob_bytes)
+  }
+
+  /// Non-blocking variant of `blob_to_commitment`: the computation runs on libuv's threadpool
+  /// instead of the Node main thread.
+  /// - `blob_bytes`: The blob byte array.
+  /// Returns: A `Promise` resolving to the commitment hex string.
+  #[napi]
+  pub fn blob_to_commitment_async(&self, blob_bytes: Uint8Array) -> AsyncTask<BlobToCommitmentTask> {
+    AsyncTask::new(BlobToCommitmentTask {
+      wrapper: self.clone_for_task(),
+      blob_bytes,
+    })
+  }
+
   /// **Batch** and **concurrently** converts multiple blobs to KZG commitments using Rayon.
   /// - `blobs_bytes`: A vector of blob byte arrays.
   /// Returns: A vector of commitment strings.
@@ -64,6 +232,32 @@ impl KzgWrapper {
       .collect()
   }
 
+  /// **Batch** raw-bytes variant of `blob_to_commitment_batch`: skips the hex round trip entirely.
+  /// - `blobs_bytes`: A vector of blob byte arrays.
+  /// Returns: A vector of raw 48-byte commitments.
+  #[napi]
+  pub fn blob_to_commitment_batch_raw_bytes(&self, blobs_bytes: Vec<Uint8Array>) -> Result<Vec<Buffer>> {
+    blobs_bytes
+      .par_iter()
+      .map(|blob_bytes| self.process_single_commitment_raw(blob_bytes))
+      .collect()
+  }
+
+  /// Non-blocking variant of `blob_to_commitment_batch`, dispatched to libuv's threadpool.
+  /// The rayon fan-out still happens internally once the task runs.
+  /// - `blobs_bytes`: A vector of blob byte arrays.
+  /// Returns: A `Promise` resolving to a vector of commitment strings.
+  #[napi]
+  pub fn blob_to_commitment_batch_async(
+    &self,
+    blobs_bytes: Vec<Uint8Array>,
+  ) -> AsyncTask<BlobToCommitmentBatchTask> {
+    AsyncTask::new(BlobToCommitmentBatchTask {
+      wrapper: self.clone_for_task(),
+      blobs_bytes,
+    })
+  }
+
   /// Computes the KZG proofs for all cells of a single blob.
   /// - `blob_bytes`: The blob byte array.
   /// Returns: An array of proof strings (each prefixed with "0x").
@@ -72,6 +266,28 @@ impl KzgWrapper {
     self.process_single_blob_proof(&blob_bytes)
   }
 
+  /// Raw-bytes variant of `compute_cell_proofs` for throughput-sensitive callers: skips the hex
+  /// round trip and returns the proofs as one flat, `BYTES_PER_PROOF`-stride buffer (input blobs
+  /// are still copied once into a fixed-size array, as the backend's entry points take blobs by
+  /// value).
+  /// - `blob_bytes`: The blob byte array.
+  /// Returns: The raw, flat-packed proof bytes.
+  #[napi]
+  pub fn compute_cell_proofs_raw_bytes(&self, blob_bytes: Uint8Array) -> Result<Buffer> {
+    self.process_single_blob_proof_raw(&blob_bytes)
+  }
+
+  /// Non-blocking variant of `compute_cell_proofs`, dispatched to libuv's threadpool.
+  /// - `blob_bytes`: The blob byte array.
+  /// Returns: A `Promise` resolving to an array of proof strings.
+  #[napi]
+  pub fn compute_cell_proofs_async(&self, blob_bytes: Uint8Array) -> AsyncTask<ComputeCellProofsTask> {
+    AsyncTask::new(ComputeCellProofsTask {
+      wrapper: self.clone_for_task(),
+      blob_bytes,
+    })
+  }
+
   /// **Batch** and **concurrently** computes cell KZG proofs for multiple blobs using Rayon.
   /// - `blobs_bytes`: A vector of blob byte arrays.
   /// Returns: A 2D array of proof strings.
@@ -86,13 +302,411 @@ impl KzgWrapper {
       .collect()
   }
 
+  /// **Batch** raw-bytes variant of `compute_cell_proofs_batch`: skips the hex round trip, each
+  /// blob's proofs packed into one flat, `BYTES_PER_PROOF`-stride buffer.
+  /// - `blobs_bytes`: A vector of blob byte arrays.
+  /// Returns: A vector of raw, flat-packed proof bytes, one per blob.
+  #[napi]
+  pub fn compute_cell_proofs_batch_raw_bytes(
+    &self,
+    blobs_bytes: Vec<Uint8Array>,
+  ) -> Result<Vec<Buffer>> {
+    blobs_bytes
+      .par_iter()
+      .map(|blob_bytes| self.process_single_blob_proof_raw(blob_bytes))
+      .collect()
+  }
+
+  /// Non-blocking variant of `compute_cell_proofs_batch`, dispatched to libuv's threadpool.
+  /// The rayon fan-out still happens internally once the task runs.
+  /// - `blobs_bytes`: A vector of blob byte arrays.
+  /// Returns: A `Promise` resolving to a 2D array of proof strings.
+  #[napi]
+  pub fn compute_cell_proofs_batch_async(
+    &self,
+    blobs_bytes: Vec<Uint8Array>,
+  ) -> AsyncTask<ComputeCellProofsBatchTask> {
+    AsyncTask::new(ComputeCellProofsBatchTask {
+      wrapper: self.clone_for_task(),
+      blobs_bytes,
+    })
+  }
+
+  /// **Batch** and **concurrently** verifies a set of received EIP-7594 cells against their
+  /// commitments using Rayon.
+  /// - `commitments`, `proofs`: Hex string or raw bytes, one per cell.
+  /// - `cell_indices`, `cells`: Parallel arrays, one entry per cell (index must be `< CELLS_PER_EXT_BLOB`).
+  /// Returns: Whether every cell in the batch verifies successfully.
+  #[napi]
+  pub fn verify_cell_kzg_proof_batch(
+    &self,
+    commitments: Vec<HexOrBytes>,
+    cell_indices: Vec<u32>,
+    cells: Vec<Uint8Array>,
+    proofs: Vec<HexOrBytes>,
+  ) -> Result<bool> {
+    self.process_cell_kzg_proof_batch_verification(&commitments, &cell_indices, &cells, &proofs)
+  }
+
+  /// Non-blocking variant of `verify_cell_kzg_proof_batch`, dispatched to libuv's threadpool.
+  /// The rayon fan-out still happens internally once the task runs.
+  /// Returns: A `Promise` resolving to whether every cell in the batch verifies successfully.
+  #[napi]
+  pub fn verify_cell_kzg_proof_batch_async(
+    &self,
+    commitments: Vec<HexOrBytes>,
+    cell_indices: Vec<u32>,
+    cells: Vec<Uint8Array>,
+    proofs: Vec<HexOrBytes>,
+  ) -> AsyncTask<VerifyCellKzgProofBatchTask> {
+    AsyncTask::new(VerifyCellKzgProofBatchTask {
+      wrapper: self.clone_for_task(),
+      commitments,
+      cell_indices,
+      cells,
+      proofs,
+    })
+  }
+
+  /// Recovers the full 128-cell set (and matching proofs) of a blob from a partial subset,
+  /// via Reed-Solomon erasure decoding over the extended evaluation domain.
+  /// - `cell_indices`, `cells`: Parallel arrays of at least `CELLS_PER_EXT_BLOB / 2` distinct,
+  ///   in-range cells.
+  /// Returns: All 128 recovered cells and their KZG proofs.
+  #[napi]
+  pub fn recover_cells_and_kzg_proofs(
+    &self,
+    cell_indices: Vec<u32>,
+    cells: Vec<Uint8Array>,
+  ) -> Result<RecoveredCells> {
+    self.process_cell_recovery(&cell_indices, &cells)
+  }
+
+  /// Non-blocking variant of `recover_cells_and_kzg_proofs`, dispatched to libuv's threadpool —
+  /// the single heaviest operation in the crate, so it benefits the most from not blocking the
+  /// Node main thread.
+  /// Returns: A `Promise` resolving to all 128 recovered cells and their KZG proofs.
+  #[napi]
+  pub fn recover_cells_and_kzg_proofs_async(
+    &self,
+    cell_indices: Vec<u32>,
+    cells: Vec<Uint8Array>,
+  ) -> AsyncTask<RecoverCellsAndKzgProofsTask> {
+    AsyncTask::new(RecoverCellsAndKzgProofsTask {
+      wrapper: self.clone_for_task(),
+      cell_indices,
+      cells,
+    })
+  }
+
+  /// Verifies that `proof` attests that `blob` opens to `commitment`.
+  /// - `blob_bytes`: The blob byte array.
+  /// - `commitment`, `proof`: Hex string or raw bytes.
+  /// Returns: Whether the proof is valid.
+  #[napi]
+  pub fn verify_blob_kzg_proof(
+    &self,
+    blob_bytes: Uint8Array,
+    commitment: HexOrBytes,
+    proof: HexOrBytes,
+  ) -> Result<bool> {
+    self.process_single_blob_proof_verification(&blob_bytes, &commitment, &proof)
+  }
+
+  /// Non-blocking variant of `verify_blob_kzg_proof`, dispatched to libuv's threadpool.
+  /// Returns: A `Promise` resolving to whether the proof is valid.
+  #[napi]
+  pub fn verify_blob_kzg_proof_async(
+    &self,
+    blob_bytes: Uint8Array,
+    commitment: HexOrBytes,
+    proof: HexOrBytes,
+  ) -> AsyncTask<VerifyBlobKzgProofTask> {
+    AsyncTask::new(VerifyBlobKzgProofTask {
+      wrapper: self.clone_for_task(),
+      blob_bytes,
+      commitment,
+      proof,
+    })
+  }
+
+  /// **Batch** and **concurrently** verifies blob/commitment/proof triples using Rayon.
+  /// - `blobs_bytes`, `commitments`, `proofs`: Parallel arrays, must be the same length.
+  /// Returns: Whether every triple verifies successfully.
+  #[napi]
+  pub fn verify_blob_kzg_proof_batch(
+    &self,
+    blobs_bytes: Vec<Uint8Array>,
+    commitments: Vec<HexOrBytes>,
+    proofs: Vec<HexOrBytes>,
+  ) -> Result<bool> {
+    self.process_blob_kzg_proof_batch_verification(&blobs_bytes, &commitments, &proofs)
+  }
+
+  /// Non-blocking variant of `verify_blob_kzg_proof_batch`, dispatched to libuv's threadpool.
+  /// The rayon fan-out still happens internally once the task runs.
+  /// Returns: A `Promise` resolving to whether every triple verifies successfully.
+  #[napi]
+  pub fn verify_blob_kzg_proof_batch_async(
+    &self,
+    blobs_bytes: Vec<Uint8Array>,
+    commitments: Vec<HexOrBytes>,
+    proofs: Vec<HexOrBytes>,
+  ) -> AsyncTask<VerifyBlobKzgProofBatchTask> {
+    AsyncTask::new(VerifyBlobKzgProofBatchTask {
+      wrapper: self.clone_for_task(),
+      blobs_bytes,
+      commitments,
+      proofs,
+    })
+  }
+
+  /// Point-evaluation check that `commitment` opens to `y` at point `z`, attested by `proof`.
+  /// - `commitment`, `z`, `y`, `proof`: Hex string or raw bytes.
+  /// Returns: Whether the proof is valid.
+  #[napi]
+  pub fn verify_kzg_proof(
+    &self,
+    commitment: HexOrBytes,
+    z: HexOrBytes,
+    y: HexOrBytes,
+    proof: HexOrBytes,
+  ) -> Result<bool> {
+    self.process_single_kzg_proof_verification(&commitment, &z, &y, &proof)
+  }
+
+  /// Non-blocking variant of `verify_kzg_proof`, dispatched to libuv's threadpool.
+  /// Returns: A `Promise` resolving to whether the proof is valid.
+  #[napi]
+  pub fn verify_kzg_proof_async(
+    &self,
+    commitment: HexOrBytes,
+    z: HexOrBytes,
+    y: HexOrBytes,
+    proof: HexOrBytes,
+  ) -> AsyncTask<VerifyKzgProofTask> {
+    AsyncTask::new(VerifyKzgProofTask {
+      wrapper: self.clone_for_task(),
+      commitment,
+      z,
+      y,
+      proof,
+    })
+  }
+
   // ------------------ Internal Reusable Logic ------------------
 
+  /// Internal: Verifies a single blob/commitment/proof triple (reused by single and batch methods).
+  fn process_single_blob_proof_verification(
+    &self,
+    blob_bytes: &Uint8Array,
+    commitment: &HexOrBytes,
+    proof: &HexOrBytes,
+  ) -> Result<bool> {
+    let blob_array = self.parse_blob_array(blob_bytes)?;
+    let commitment_bytes: [u8; 48] = Self::parse_fixed_bytes(commitment, "commitment")?;
+    let proof_bytes: [u8; 48] = Self::parse_fixed_bytes(proof, "proof")?;
+
+    verify_blob_kzg_proof_raw(blob_array, &commitment_bytes, &proof_bytes, self.settings.as_ref()).map_err(
+      |e| {
+        Error::new(
+          Status::GenericFailure,
+          format!("Failed to verify blob KZG proof: {:?}", e),
+        )
+      },
+    )
+  }
+
+  /// Internal: Verifies a batch of blob/commitment/proof triples using Rayon (reused by the
+  /// sync and async batch methods).
+  fn process_blob_kzg_proof_batch_verification(
+    &self,
+    blobs_bytes: &[Uint8Array],
+    commitments: &[HexOrBytes],
+    proofs: &[HexOrBytes],
+  ) -> Result<bool> {
+    if blobs_bytes.len() != commitments.len() || blobs_bytes.len() != proofs.len() {
+      return Err(Error::new(
+        Status::InvalidArg,
+        "blobs, commitments and proofs must have the same length".to_string(),
+      ));
+    }
+
+    let results: Result<Vec<bool>> = blobs_bytes
+      .par_iter()
+      .zip(commitments.par_iter())
+      .zip(proofs.par_iter())
+      .map(|((blob_bytes, commitment), proof)| {
+        self.process_single_blob_proof_verification(blob_bytes, commitment, proof)
+      })
+      .collect();
+
+    Ok(results?.into_iter().all(|verified| verified))
+  }
+
+  /// Internal: Performs the point-evaluation check (reused by the sync and async methods).
+  fn process_single_kzg_proof_verification(
+    &self,
+    commitment: &HexOrBytes,
+    z: &HexOrBytes,
+    y: &HexOrBytes,
+    proof: &HexOrBytes,
+  ) -> Result<bool> {
+    let commitment_bytes: [u8; 48] = Self::parse_fixed_bytes(commitment, "commitment")?;
+    let z_bytes: [u8; 32] = Self::parse_fixed_bytes(z, "z")?;
+    let y_bytes: [u8; 32] = Self::parse_fixed_bytes(y, "y")?;
+    let proof_bytes: [u8; 48] = Self::parse_fixed_bytes(proof, "proof")?;
+
+    verify_kzg_proof_raw(
+      &commitment_bytes,
+      &z_bytes,
+      &y_bytes,
+      &proof_bytes,
+      self.settings.as_ref(),
+    )
+    .map_err(|e| {
+      Error::new(
+        Status::GenericFailure,
+        format!("Failed to verify KZG proof: {:?}", e),
+      )
+    })
+  }
+
+  /// Internal: Verifies a batch of received EIP-7594 cells using Rayon (reused by the sync and
+  /// async methods).
+  fn process_cell_kzg_proof_batch_verification(
+    &self,
+    commitments: &[HexOrBytes],
+    cell_indices: &[u32],
+    cells: &[Uint8Array],
+    proofs: &[HexOrBytes],
+  ) -> Result<bool> {
+    if commitments.len() != cell_indices.len()
+      || cell_indices.len() != cells.len()
+      || cells.len() != proofs.len()
+    {
+      return Err(Error::new(
+        Status::InvalidArg,
+        "commitments, cell_indices, cells and proofs must have the same length".to_string(),
+      ));
+    }
+
+    for index in cell_indices {
+      if *index as usize >= CELLS_PER_EXT_BLOB {
+        return Err(Error::new(
+          Status::InvalidArg,
+          format!(
+            "Cell index {} out of range, must be < {}",
+            index, CELLS_PER_EXT_BLOB
+          ),
+        ));
+      }
+    }
+
+    let commitment_bytes: Vec<[u8; 48]> = commitments
+      .par_iter()
+      .map(|commitment| Self::parse_fixed_bytes(commitment, "commitment"))
+      .collect::<Result<_>>()?;
+    let proof_bytes: Vec<[u8; BYTES_PER_PROOF]> = proofs
+      .par_iter()
+      .map(|proof| Self::parse_fixed_bytes(proof, "proof"))
+      .collect::<Result<_>>()?;
+    let cell_arrays: Vec<[u8; BYTES_PER_CELL]> = cells
+      .par_iter()
+      .map(Self::parse_cell_array)
+      .collect::<Result<_>>()?;
+    let indices: Vec<u64> = cell_indices.iter().map(|&index| index as u64).collect();
+
+    verify_cell_kzg_proof_batch_raw::<BlstBackend>(
+      &commitment_bytes,
+      &indices,
+      &cell_arrays,
+      &proof_bytes,
+      self.settings.as_ref(),
+    )
+    .map_err(|e| {
+      Error::new(
+        Status::GenericFailure,
+        format!("Failed to verify cell KZG proof batch: {}", e),
+      )
+    })
+  }
+
+  /// Internal: Recovers the full 128-cell set (and matching proofs) from a partial subset
+  /// (reused by the sync and async methods).
+  fn process_cell_recovery(
+    &self,
+    cell_indices: &[u32],
+    cells: &[Uint8Array],
+  ) -> Result<RecoveredCells> {
+    if cell_indices.len() != cells.len() {
+      return Err(Error::new(
+        Status::InvalidArg,
+        "cell_indices and cells must have the same length".to_string(),
+      ));
+    }
+    if cell_indices.len() < CELLS_PER_EXT_BLOB / 2 {
+      return Err(Error::new(
+        Status::InvalidArg,
+        format!(
+          "Recovery requires at least {} cells, got {}",
+          CELLS_PER_EXT_BLOB / 2,
+          cell_indices.len()
+        ),
+      ));
+    }
+
+    let mut seen_indices = HashSet::with_capacity(cell_indices.len());
+    for index in cell_indices {
+      if *index as usize >= CELLS_PER_EXT_BLOB {
+        return Err(Error::new(
+          Status::InvalidArg,
+          format!(
+            "Cell index {} out of range, must be < {}",
+            index, CELLS_PER_EXT_BLOB
+          ),
+        ));
+      }
+      if !seen_indices.insert(*index) {
+        return Err(Error::new(
+          Status::InvalidArg,
+          format!("Duplicate cell index {}", index),
+        ));
+      }
+    }
+
+    let cell_arrays: Vec<[u8; BYTES_PER_CELL]> = cells
+      .par_iter()
+      .map(Self::parse_cell_array)
+      .collect::<Result<_>>()?;
+    let indices: Vec<u64> = cell_indices.iter().map(|&index| index as u64).collect();
+
+    let (recovered_cells, recovered_proofs) =
+      recover_cells_and_kzg_proofs_raw::<BlstBackend>(&indices, &cell_arrays, self.settings.as_ref())
+        .map_err(|e| {
+          Error::new(
+            Status::GenericFailure,
+            format!("Failed to recover cells and KZG proofs: {}", e),
+          )
+        })?;
+
+    Ok(RecoveredCells {
+      cells: recovered_cells
+        .into_iter()
+        .map(|cell: [u8; BYTES_PER_CELL]| format!("0x{}", encode(cell)))
+        .collect(),
+      proofs: recovered_proofs
+        .into_iter()
+        .map(|proof: [u8; BYTES_PER_PROOF]| format!("0x{}", encode(proof)))
+        .collect(),
+    })
+  }
+
   /// Internal: Processes a single blob to commitment (reused by single and batch methods).
   fn process_single_commitment(&self, blob_bytes: &Uint8Array) -> Result<String> {
-    let blob_array = Self::parse_blob_array(blob_bytes)?;
+    let blob_array = self.parse_blob_array(blob_bytes)?;
 
-    let commitment = blob_to_kzg_commitment_raw(blob_array, &self.settings).map_err(|e| {
+    let commitment = blob_to_kzg_commitment_raw(blob_array, self.settings.as_ref()).map_err(|e| {
       Error::new(
         Status::GenericFailure,
         format!("Failed to convert blob to commitment: {:?}", e),
@@ -105,10 +719,10 @@ impl KzgWrapper {
 
   /// Internal: Processes a single blob to compute Cell proofs (reused by single and batch methods).
   fn process_single_blob_proof(&self, blob_bytes: &Uint8Array) -> Result<Vec<String>> {
-    let blob_array = Self::parse_blob_array(blob_bytes)?;
+    let blob_array = self.parse_blob_array(blob_bytes)?;
 
     let (_, proofs): CellsKzgProofs =
-      compute_cells_and_kzg_proofs_raw::<BlstBackend>(blob_array, &self.settings).map_err(|e| {
+      compute_cells_and_kzg_proofs_raw::<BlstBackend>(blob_array, self.settings.as_ref()).map_err(|e| {
         Error::new(
           Status::GenericFailure,
           format!("Failed to compute cell proofs: {}", e),
@@ -124,21 +738,426 @@ impl KzgWrapper {
     Ok(proof_strings)
   }
 
-  /// Universal: Converts Uint8Array to a fixed-size blob array, validating length.
-  fn parse_blob_array(blob_bytes: &Uint8Array) -> Result<[u8; BYTES_PER_BLOB]> {
+  /// Internal: Processes a single blob to a raw 48-byte commitment, skipping hex entirely
+  /// (reused by single and batch raw-bytes methods).
+  fn process_single_commitment_raw(&self, blob_bytes: &Uint8Array) -> Result<Buffer> {
+    let blob_array = self.parse_blob_array(blob_bytes)?;
+
+    let commitment = blob_to_kzg_commitment_raw(blob_array, self.settings.as_ref()).map_err(|e| {
+      Error::new(
+        Status::GenericFailure,
+        format!("Failed to convert blob to commitment: {:?}", e),
+      )
+    })?;
+
+    let commitment_bytes: [u8; 48] = G1::to_bytes(&commitment);
+    Ok(commitment_bytes.to_vec().into())
+  }
+
+  /// Internal: Processes a single blob to raw, flat-packed cell proofs, skipping hex entirely
+  /// (reused by single and batch raw-bytes methods).
+  fn process_single_blob_proof_raw(&self, blob_bytes: &Uint8Array) -> Result<Buffer> {
+    let blob_array = self.parse_blob_array(blob_bytes)?;
+
+    let (_, proofs): CellsKzgProofs =
+      compute_cells_and_kzg_proofs_raw::<BlstBackend>(blob_array, self.settings.as_ref()).map_err(|e| {
+        Error::new(
+          Status::GenericFailure,
+          format!("Failed to compute cell proofs: {}", e),
+        )
+      })?;
+
+    let mut flat_proofs = Vec::with_capacity(proofs.len() * BYTES_PER_PROOF);
+    for proof_bytes in proofs {
+      flat_proofs.extend_from_slice(&proof_bytes);
+    }
+
+    Ok(flat_proofs.into())
+  }
+
+  /// Universal: Converts Uint8Array to a fixed-size, mainnet-sized blob array, validating
+  /// against the configured maximum length and zero-padding under-length input up to it. The
+  /// backend always operates on the full `BYTES_PER_BLOB` array regardless of this padding.
+  ///
+  /// One copy out of the JS buffer is unavoidable here: `blob_to_kzg_commitment_raw` and
+  /// `compute_cells_and_kzg_proofs_raw` both take the blob by value (`[u8; BYTES_PER_BLOB]`), so
+  /// the backend itself requires an owned array, not a borrow. What this *does* avoid is the
+  /// redundant zero-fill pass for the common case where the input is already exactly
+  /// `BYTES_PER_BLOB` long and no padding is needed.
+  fn parse_blob_array(&self, blob_bytes: &Uint8Array) -> Result<[u8; BYTES_PER_BLOB]> {
     let slice = blob_bytes.as_ref();
-    if slice.len() != BYTES_PER_BLOB {
+    if slice.len() > self.bytes_per_blob {
       return Err(Error::new(
         Status::InvalidArg,
         format!(
-          "Invalid blob length: expected {} bytes, got {} bytes",
-          BYTES_PER_BLOB,
+          "Invalid blob length: expected at most {} bytes (configured maximum), got {} bytes",
+          self.bytes_per_blob,
           slice.len()
         ),
       ));
     }
+
+    if slice.len() == BYTES_PER_BLOB {
+      return Ok(slice.try_into().expect("length checked above"));
+    }
+
     let mut blob_array = [0u8; BYTES_PER_BLOB];
-    blob_array.copy_from_slice(slice);
+    blob_array[..slice.len()].copy_from_slice(slice);
     Ok(blob_array)
   }
+
+  /// Internal: Rejects a trusted setup whose G1 point count isn't mainnet-sized. The backend's
+  /// FFT domain is always derived from this count, while `parse_blob_array` always produces a
+  /// mainnet-sized (`BYTES_PER_BLOB`) array; a smaller or larger domain would either panic against
+  /// that array or silently produce a commitment that isn't valid for the declared domain.
+  fn validate_mainnet_point_count(point_count: usize, label: &str) -> Result<()> {
+    if point_count != FIELD_ELEMENTS_PER_BLOB {
+      return Err(Error::new(
+        Status::InvalidArg,
+        format!(
+          "{} declares {} field elements per blob, but only the mainnet size ({}) is supported",
+          label, point_count, FIELD_ELEMENTS_PER_BLOB
+        ),
+      ));
+    }
+    Ok(())
+  }
+
+  /// Internal: Rejects a G1 point byte array whose length isn't exactly mainnet-sized. See
+  /// `validate_mainnet_point_count` for why this matters.
+  fn validate_mainnet_g1_byte_length(byte_length: usize, label: &str) -> Result<()> {
+    let expected = FIELD_ELEMENTS_PER_BLOB * BYTES_PER_G1_POINT;
+    if byte_length != expected {
+      return Err(Error::new(
+        Status::InvalidArg,
+        format!(
+          "{} is {} bytes, but the mainnet size ({} bytes, {} points) is required",
+          label, byte_length, expected, FIELD_ELEMENTS_PER_BLOB
+        ),
+      ));
+    }
+    Ok(())
+  }
+
+  /// Internal: Resolves the configured maximum blob length in bytes from an optional
+  /// field-element count, defaulting to the mainnet `BYTES_PER_BLOB` when none is given. This
+  /// only bounds/pads input length — it does not change the trusted setup or FFT domain size.
+  fn resolve_bytes_per_blob(field_elements_per_blob: Option<u32>) -> Result<usize> {
+    let Some(field_elements_per_blob) = field_elements_per_blob else {
+      return Ok(BYTES_PER_BLOB);
+    };
+
+    if field_elements_per_blob == 0 {
+      return Err(Error::new(
+        Status::InvalidArg,
+        "field_elements_per_blob must be greater than zero".to_string(),
+      ));
+    }
+
+    let bytes_per_blob = field_elements_per_blob as usize * BYTES_PER_FIELD_ELEMENT;
+    if bytes_per_blob > BYTES_PER_BLOB {
+      return Err(Error::new(
+        Status::InvalidArg,
+        format!(
+          "field_elements_per_blob of {} bytes exceeds the maximum supported {} bytes",
+          bytes_per_blob, BYTES_PER_BLOB
+        ),
+      ));
+    }
+
+    Ok(bytes_per_blob)
+  }
+
+  /// Internal: Decodes and concatenates a JSON bundle's hex-encoded points into raw bytes.
+  fn concat_hex_points(points: &[String]) -> Result<Vec<u8>> {
+    points.iter().try_fold(Vec::new(), |mut acc, point| {
+      let trimmed = point.strip_prefix("0x").unwrap_or(point);
+      let bytes = decode(trimmed).map_err(|e| {
+        Error::new(
+          Status::InvalidArg,
+          format!("Invalid hex point in trusted setup JSON: {:?}", e),
+        )
+      })?;
+      acc.extend_from_slice(&bytes);
+      Ok(acc)
+    })
+  }
+
+  /// Internal: Clones into a fresh wrapper, which an `AsyncTask` must own since it runs on
+  /// libuv's threadpool rather than borrowing from `&self`. `settings` is shared via `Arc`, so
+  /// this is a refcount bump, not a deep copy of the trusted setup/FFT tables.
+  fn clone_for_task(&self) -> Self {
+    Self {
+      settings: self.settings.clone(),
+      bytes_per_blob: self.bytes_per_blob,
+    }
+  }
+
+  /// Universal: Converts Uint8Array to a fixed-size cell array, validating length.
+  fn parse_cell_array(cell_bytes: &Uint8Array) -> Result<[u8; BYTES_PER_CELL]> {
+    let slice = cell_bytes.as_ref();
+    if slice.len() != BYTES_PER_CELL {
+      return Err(Error::new(
+        Status::InvalidArg,
+        format!(
+          "Invalid cell length: expected {} bytes, got {} bytes",
+          BYTES_PER_CELL,
+          slice.len()
+        ),
+      ));
+    }
+    let mut cell_array = [0u8; BYTES_PER_CELL];
+    cell_array.copy_from_slice(slice);
+    Ok(cell_array)
+  }
+
+  /// Universal: Converts a hex string or raw byte input into a `Vec<u8>`, decoding an optional
+  /// "0x" prefix symmetrically with how outputs are produced.
+  fn bytes_from_input(input: &HexOrBytes) -> Result<Vec<u8>> {
+    match input {
+      Either::A(hex_string) => {
+        let trimmed = hex_string.strip_prefix("0x").unwrap_or(hex_string);
+        decode(trimmed).map_err(|e| {
+          Error::new(
+            Status::InvalidArg,
+            format!("Invalid hex string: {:?}", e),
+          )
+        })
+      }
+      Either::B(bytes) => Ok(bytes.as_ref().to_vec()),
+    }
+  }
+
+  /// Universal: Converts a hex string or raw byte input into a fixed-size array, validating length.
+  fn parse_fixed_bytes<const N: usize>(input: &HexOrBytes, label: &str) -> Result<[u8; N]> {
+    let bytes = Self::bytes_from_input(input)?;
+    if bytes.len() != N {
+      return Err(Error::new(
+        Status::InvalidArg,
+        format!(
+          "Invalid {} length: expected {} bytes, got {} bytes",
+          label,
+          N,
+          bytes.len()
+        ),
+      ));
+    }
+    let mut array = [0u8; N];
+    array.copy_from_slice(&bytes);
+    Ok(array)
+  }
+}
+
+// ------------------ Async Tasks ------------------
+//
+// Each task owns a cloned `KzgWrapper` so the heavy KZG work can run on libuv's threadpool
+// (via `compute`) instead of blocking the Node main thread; `resolve` then hands the already
+// computed value back to JS.
+
+/// Backs `blob_to_commitment_async`.
+pub struct BlobToCommitmentTask {
+  wrapper: KzgWrapper,
+  blob_bytes: Uint8Array,
+}
+
+impl Task for BlobToCommitmentTask {
+  type Output = String;
+  type JsValue = String;
+
+  fn compute(&mut self) -> Result<Self::Output> {
+    self.wrapper.process_single_commitment(&self.blob_bytes)
+  }
+
+  fn resolve(&mut self, _env: Env, output: Self::Output) -> Result<Self::JsValue> {
+    Ok(output)
+  }
+}
+
+/// Backs `blob_to_commitment_batch_async`.
+pub struct BlobToCommitmentBatchTask {
+  wrapper: KzgWrapper,
+  blobs_bytes: Vec<Uint8Array>,
+}
+
+impl Task for BlobToCommitmentBatchTask {
+  type Output = Vec<String>;
+  type JsValue = Vec<String>;
+
+  fn compute(&mut self) -> Result<Self::Output> {
+    self
+      .blobs_bytes
+      .par_iter()
+      .map(|blob_bytes| self.wrapper.process_single_commitment(blob_bytes))
+      .collect()
+  }
+
+  fn resolve(&mut self, _env: Env, output: Self::Output) -> Result<Self::JsValue> {
+    Ok(output)
+  }
+}
+
+/// Backs `compute_cell_proofs_async`.
+pub struct ComputeCellProofsTask {
+  wrapper: KzgWrapper,
+  blob_bytes: Uint8Array,
+}
+
+impl Task for ComputeCellProofsTask {
+  type Output = Vec<String>;
+  type JsValue = Vec<String>;
+
+  fn compute(&mut self) -> Result<Self::Output> {
+    self.wrapper.process_single_blob_proof(&self.blob_bytes)
+  }
+
+  fn resolve(&mut self, _env: Env, output: Self::Output) -> Result<Self::JsValue> {
+    Ok(output)
+  }
+}
+
+/// Backs `compute_cell_proofs_batch_async`.
+pub struct ComputeCellProofsBatchTask {
+  wrapper: KzgWrapper,
+  blobs_bytes: Vec<Uint8Array>,
+}
+
+impl Task for ComputeCellProofsBatchTask {
+  type Output = Vec<Vec<String>>;
+  type JsValue = Vec<Vec<String>>;
+
+  fn compute(&mut self) -> Result<Self::Output> {
+    self
+      .blobs_bytes
+      .par_iter()
+      .map(|blob_bytes| self.wrapper.process_single_blob_proof(blob_bytes))
+      .collect()
+  }
+
+  fn resolve(&mut self, _env: Env, output: Self::Output) -> Result<Self::JsValue> {
+    Ok(output)
+  }
+}
+
+/// Backs `verify_blob_kzg_proof_async`.
+pub struct VerifyBlobKzgProofTask {
+  wrapper: KzgWrapper,
+  blob_bytes: Uint8Array,
+  commitment: HexOrBytes,
+  proof: HexOrBytes,
+}
+
+impl Task for VerifyBlobKzgProofTask {
+  type Output = bool;
+  type JsValue = bool;
+
+  fn compute(&mut self) -> Result<Self::Output> {
+    self
+      .wrapper
+      .process_single_blob_proof_verification(&self.blob_bytes, &self.commitment, &self.proof)
+  }
+
+  fn resolve(&mut self, _env: Env, output: Self::Output) -> Result<Self::JsValue> {
+    Ok(output)
+  }
+}
+
+/// Backs `verify_blob_kzg_proof_batch_async`.
+pub struct VerifyBlobKzgProofBatchTask {
+  wrapper: KzgWrapper,
+  blobs_bytes: Vec<Uint8Array>,
+  commitments: Vec<HexOrBytes>,
+  proofs: Vec<HexOrBytes>,
+}
+
+impl Task for VerifyBlobKzgProofBatchTask {
+  type Output = bool;
+  type JsValue = bool;
+
+  fn compute(&mut self) -> Result<Self::Output> {
+    self.wrapper.process_blob_kzg_proof_batch_verification(
+      &self.blobs_bytes,
+      &self.commitments,
+      &self.proofs,
+    )
+  }
+
+  fn resolve(&mut self, _env: Env, output: Self::Output) -> Result<Self::JsValue> {
+    Ok(output)
+  }
+}
+
+/// Backs `verify_kzg_proof_async`.
+pub struct VerifyKzgProofTask {
+  wrapper: KzgWrapper,
+  commitment: HexOrBytes,
+  z: HexOrBytes,
+  y: HexOrBytes,
+  proof: HexOrBytes,
+}
+
+impl Task for VerifyKzgProofTask {
+  type Output = bool;
+  type JsValue = bool;
+
+  fn compute(&mut self) -> Result<Self::Output> {
+    self.wrapper.process_single_kzg_proof_verification(
+      &self.commitment,
+      &self.z,
+      &self.y,
+      &self.proof,
+    )
+  }
+
+  fn resolve(&mut self, _env: Env, output: Self::Output) -> Result<Self::JsValue> {
+    Ok(output)
+  }
+}
+
+/// Backs `verify_cell_kzg_proof_batch_async`.
+pub struct VerifyCellKzgProofBatchTask {
+  wrapper: KzgWrapper,
+  commitments: Vec<HexOrBytes>,
+  cell_indices: Vec<u32>,
+  cells: Vec<Uint8Array>,
+  proofs: Vec<HexOrBytes>,
+}
+
+impl Task for VerifyCellKzgProofBatchTask {
+  type Output = bool;
+  type JsValue = bool;
+
+  fn compute(&mut self) -> Result<Self::Output> {
+    self.wrapper.process_cell_kzg_proof_batch_verification(
+      &self.commitments,
+      &self.cell_indices,
+      &self.cells,
+      &self.proofs,
+    )
+  }
+
+  fn resolve(&mut self, _env: Env, output: Self::Output) -> Result<Self::JsValue> {
+    Ok(output)
+  }
+}
+
+/// Backs `recover_cells_and_kzg_proofs_async`.
+pub struct RecoverCellsAndKzgProofsTask {
+  wrapper: KzgWrapper,
+  cell_indices: Vec<u32>,
+  cells: Vec<Uint8Array>,
+}
+
+impl Task for RecoverCellsAndKzgProofsTask {
+  type Output = RecoveredCells;
+  type JsValue = RecoveredCells;
+
+  fn compute(&mut self) -> Result<Self::Output> {
+    self
+      .wrapper
+      .process_cell_recovery(&self.cell_indices, &self.cells)
+  }
+
+  fn resolve(&mut self, _env: Env, output: Self::Output) -> Result<Self::JsValue> {
+    Ok(output)
+  }
 }